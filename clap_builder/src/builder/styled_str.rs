@@ -136,6 +136,63 @@ impl StyledStr {
 
         Ok(())
     }
+
+    /// Render using [ANSI Escape Code](https://en.wikipedia.org/wiki/ANSI_escape_code) styling,
+    /// downgrading any colors `capability` can't represent to the nearest one it can
+    #[cfg(feature = "color")]
+    pub fn ansi_capped(&self, capability: ColorCapability) -> impl std::fmt::Display + '_ {
+        color_degrade::downgrade(&self.0, capability)
+    }
+
+    /// Write to `buffer`, downgrading any colors `capability` can't represent to the nearest one
+    /// it can
+    #[cfg(feature = "color")]
+    pub fn write_to_capped(
+        &self,
+        capability: ColorCapability,
+        buffer: &mut dyn std::io::Write,
+    ) -> std::io::Result<()> {
+        let downgraded = color_degrade::downgrade(&self.0, capability);
+        ok!(buffer.write_all(downgraded.as_bytes()));
+
+        Ok(())
+    }
+
+    /// Write `self` to `buffer`, falling back to the Windows Console API on terminals that
+    /// don't support ANSI escapes (e.g. legacy `cmd.exe`)
+    ///
+    /// `stream` must name the standard stream `buffer` ultimately writes to, since the Windows
+    /// Console API operates on `STD_OUTPUT_HANDLE`/`STD_ERROR_HANDLE`, not on `buffer` itself;
+    /// clap's help goes to stdout and its errors to stderr, and those can be redirected
+    /// independently of each other.
+    pub fn write_to_console(
+        &self,
+        stream: Stream,
+        buffer: &mut dyn std::io::Write,
+    ) -> std::io::Result<()> {
+        #[cfg(all(windows, feature = "color"))]
+        {
+            windows_console::write(self, stream, buffer)
+        }
+        #[cfg(not(all(windows, feature = "color")))]
+        {
+            let _ = stream;
+            self.write_to(buffer)
+        }
+    }
+}
+
+/// The standard stream a [`StyledStr`] is ultimately written to
+///
+/// Needed by [`StyledStr::write_to_console`] since `buffer` alone (an arbitrary
+/// `dyn std::io::Write`) doesn't say whether it targets stdout or stderr.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Stream {
+    /// The process' standard output
+    Stdout,
+    /// The process' standard error
+    Stderr,
 }
 
 impl Default for &'_ StyledStr {
@@ -198,6 +255,274 @@ impl std::fmt::Display for StyledStr {
     }
 }
 
+/// The color depth a terminal supports, for degrading [`StyledStr`] output
+///
+/// Truecolor [`Styles`] look wrong on terminals that can't represent every RGB value, so
+/// [`StyledStr::ansi_capped`] and [`StyledStr::write_to_capped`] take one of these and rewrite
+/// any color in the buffer down to the nearest one `self` can represent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "color")]
+#[non_exhaustive]
+pub enum ColorCapability {
+    /// 24-bit RGB colors
+    TrueColor,
+    /// The 256-color palette (16 named colors, a 6x6x6 color cube, and a 24-step gray ramp)
+    Ansi256,
+    /// The original 16 ANSI colors
+    Ansi16,
+    /// No color support
+    Mono,
+}
+
+#[cfg(feature = "color")]
+impl ColorCapability {
+    /// Detect the current terminal's color capability
+    ///
+    /// Respects `NO_COLOR`, `COLORTERM`, and `TERM` the way most terminal programs do.
+    pub fn detect() -> Self {
+        if std::env::var_os("NO_COLOR").is_some() {
+            return Self::Mono;
+        }
+        if matches!(
+            std::env::var("COLORTERM").as_deref(),
+            Ok("truecolor") | Ok("24bit")
+        ) {
+            return Self::TrueColor;
+        }
+        let term = std::env::var("TERM").unwrap_or_default();
+        if term.contains("256color") {
+            Self::Ansi256
+        } else if term.is_empty() || term == "dumb" {
+            Self::Mono
+        } else {
+            Self::Ansi16
+        }
+    }
+}
+
+#[cfg(feature = "color")]
+impl Default for ColorCapability {
+    fn default() -> Self {
+        Self::detect()
+    }
+}
+
+/// Rewrites SGR color escapes down to what a [`ColorCapability`] can represent
+#[cfg(feature = "color")]
+mod color_degrade {
+    use super::ColorCapability;
+    use std::borrow::Cow;
+
+    const CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    const NAMED_16: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    pub(super) fn downgrade(input: &str, capability: ColorCapability) -> Cow<'_, str> {
+        if capability == ColorCapability::TrueColor || !input.contains('\x1b') {
+            return Cow::Borrowed(input);
+        }
+
+        let mut out = String::with_capacity(input.len());
+        let mut rest = input;
+        while let Some(start) = rest.find("\x1b[") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            if let Some(end) = after.find('m') {
+                let mut params = String::new();
+                write_params(&mut params, &after[..end], capability);
+                // Every code was a dropped color; an empty `ESC[m` would reset all attributes
+                if !params.is_empty() {
+                    out.push_str("\x1b[");
+                    out.push_str(&params);
+                    out.push('m');
+                }
+                rest = &after[end + 1..];
+            } else {
+                // Not a recognized SGR sequence; pass it through unchanged
+                out.push_str("\x1b[");
+                rest = after;
+            }
+        }
+        out.push_str(rest);
+
+        Cow::Owned(out)
+    }
+
+    fn write_params(out: &mut String, params: &str, capability: ColorCapability) {
+        let codes: Vec<u32> = params
+            .split(';')
+            .map(|p| p.parse::<u32>().unwrap_or(0))
+            .collect();
+
+        let mut kept = Vec::with_capacity(codes.len());
+        let mut i = 0;
+        while i < codes.len() {
+            let code = codes[i];
+            match code {
+                38 | 48 => {
+                    let is_fg = code == 38;
+                    match codes.get(i + 1) {
+                        Some(5) => {
+                            let idx = codes.get(i + 2).copied().unwrap_or(0) as u8;
+                            if capability == ColorCapability::Ansi256 {
+                                // Already representable exactly; don't round-trip through RGB
+                                // and risk landing on a merely-similar index.
+                                kept.extend([code, 5, u32::from(idx)]);
+                            } else {
+                                push_color(&mut kept, is_fg, ansi256_to_rgb(idx), capability);
+                            }
+                            i += 3;
+                        }
+                        Some(2) => {
+                            let rgb = (
+                                codes.get(i + 2).copied().unwrap_or(0) as u8,
+                                codes.get(i + 3).copied().unwrap_or(0) as u8,
+                                codes.get(i + 4).copied().unwrap_or(0) as u8,
+                            );
+                            push_color(&mut kept, is_fg, rgb, capability);
+                            i += 5;
+                        }
+                        _ => {
+                            kept.push(code);
+                            i += 1;
+                        }
+                    }
+                }
+                30..=39 | 90..=97 | 40..=49 | 100..=107 => {
+                    if capability != ColorCapability::Mono {
+                        kept.push(code);
+                    }
+                    i += 1;
+                }
+                _ => {
+                    kept.push(code);
+                    i += 1;
+                }
+            }
+        }
+
+        let rendered: Vec<String> = kept.iter().map(u32::to_string).collect();
+        out.push_str(&rendered.join(";"));
+    }
+
+    fn push_color(out: &mut Vec<u32>, is_fg: bool, rgb: (u8, u8, u8), capability: ColorCapability) {
+        match capability {
+            ColorCapability::TrueColor => {
+                out.extend([
+                    if is_fg { 38 } else { 48 },
+                    2,
+                    u32::from(rgb.0),
+                    u32::from(rgb.1),
+                    u32::from(rgb.2),
+                ]);
+            }
+            ColorCapability::Ansi256 => {
+                out.extend([if is_fg { 38 } else { 48 }, 5, u32::from(rgb_to_ansi256(rgb))]);
+            }
+            ColorCapability::Ansi16 => {
+                let idx = rgb_to_ansi16(rgb);
+                out.push(ansi16_code(idx, is_fg));
+            }
+            ColorCapability::Mono => {}
+        }
+    }
+
+    fn ansi16_code(idx: u8, is_fg: bool) -> u32 {
+        if idx < 8 {
+            u32::from(idx) + if is_fg { 30 } else { 40 }
+        } else {
+            u32::from(idx - 8) + if is_fg { 90 } else { 100 }
+        }
+    }
+
+    fn squared_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+        let dr = i32::from(a.0) - i32::from(b.0);
+        let dg = i32::from(a.1) - i32::from(b.1);
+        let db = i32::from(a.2) - i32::from(b.2);
+        (dr * dr + dg * dg + db * db) as u32
+    }
+
+    fn nearest_cube_index(v: u8) -> u8 {
+        CUBE_STEPS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &step)| (i32::from(step) - i32::from(v)).pow(2))
+            .map(|(i, _)| i as u8)
+            .expect("CUBE_STEPS is non-empty")
+    }
+
+    pub(super) fn rgb_to_ansi256(rgb: (u8, u8, u8)) -> u8 {
+        let (r, g, b) = rgb;
+
+        let cube_r = nearest_cube_index(r);
+        let cube_g = nearest_cube_index(g);
+        let cube_b = nearest_cube_index(b);
+        let cube_color = (
+            CUBE_STEPS[cube_r as usize],
+            CUBE_STEPS[cube_g as usize],
+            CUBE_STEPS[cube_b as usize],
+        );
+        let cube_dist = squared_distance(rgb, cube_color);
+
+        let (gray_index, gray_dist) = (0..24u8)
+            .map(|i| {
+                let value = 8 + 10 * i;
+                (i, squared_distance(rgb, (value, value, value)))
+            })
+            .min_by_key(|&(_, dist)| dist)
+            .expect("0..24 is non-empty");
+
+        if cube_dist <= gray_dist {
+            16 + 36 * cube_r + 6 * cube_g + cube_b
+        } else {
+            232 + gray_index
+        }
+    }
+
+    pub(super) fn rgb_to_ansi16(rgb: (u8, u8, u8)) -> u8 {
+        NAMED_16
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &named)| squared_distance(rgb, named))
+            .map(|(i, _)| i as u8)
+            .expect("NAMED_16 is non-empty")
+    }
+
+    pub(super) fn ansi256_to_rgb(idx: u8) -> (u8, u8, u8) {
+        match idx {
+            0..=15 => NAMED_16[idx as usize],
+            16..=231 => {
+                let i = idx - 16;
+                (
+                    CUBE_STEPS[(i / 36) as usize],
+                    CUBE_STEPS[((i / 6) % 6) as usize],
+                    CUBE_STEPS[(i % 6) as usize],
+                )
+            }
+            232..=255 => {
+                let v = 8 + 10 * (idx - 232);
+                (v, v, v)
+            }
+        }
+    }
+}
+
 /// Terminal styling definitions
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -257,6 +582,342 @@ impl Styles {
             Self::plain()
         }
     }
+
+    /// Set [`Styles::header`] from a git-style color spec (see [`Styles::parse_field`])
+    pub fn header_spec(mut self, spec: &str) -> Result<Self, ParseStylesError> {
+        self.header = Self::parse_field(spec)?;
+        Ok(self)
+    }
+
+    /// Set [`Styles::literal`] from a git-style color spec (see [`Styles::parse_field`])
+    pub fn literal_spec(mut self, spec: &str) -> Result<Self, ParseStylesError> {
+        self.literal = Self::parse_field(spec)?;
+        Ok(self)
+    }
+
+    /// Set [`Styles::placeholder`] from a git-style color spec (see [`Styles::parse_field`])
+    pub fn placeholder_spec(mut self, spec: &str) -> Result<Self, ParseStylesError> {
+        self.placeholder = Self::parse_field(spec)?;
+        Ok(self)
+    }
+
+    /// Set [`Styles::good`] from a git-style color spec (see [`Styles::parse_field`])
+    pub fn good_spec(mut self, spec: &str) -> Result<Self, ParseStylesError> {
+        self.good = Self::parse_field(spec)?;
+        Ok(self)
+    }
+
+    /// Set [`Styles::warning`] from a git-style color spec (see [`Styles::parse_field`])
+    pub fn warning_spec(mut self, spec: &str) -> Result<Self, ParseStylesError> {
+        self.warning = Self::parse_field(spec)?;
+        Ok(self)
+    }
+
+    /// Set [`Styles::error`] from a git-style color spec (see [`Styles::parse_field`])
+    pub fn error_spec(mut self, spec: &str) -> Result<Self, ParseStylesError> {
+        self.error = Self::parse_field(spec)?;
+        Ok(self)
+    }
+
+    /// Set [`Styles::hint`] from a git-style color spec (see [`Styles::parse_field`])
+    pub fn hint_spec(mut self, spec: &str) -> Result<Self, ParseStylesError> {
+        self.hint = Self::parse_field(spec)?;
+        Ok(self)
+    }
+
+    /// Parse one field of git's `color.<slot>` config grammar into a [`anstyle::Style`]
+    ///
+    /// A spec is whitespace-separated tokens: the first color token is the foreground color, the
+    /// second (if any) the background color, and every other token is an attribute; attributes
+    /// may come before, between or after the colors. Each color is one of the 8 named colors
+    /// (`black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan`, `white`), a
+    /// `bright`-prefixed variant (e.g. `brightred`), `normal`, a `0`-`255` 256-color palette
+    /// index, or a `#rrggbb` truecolor value. Each attribute is one of `bold`, `dim`,
+    /// `ul`/`underline`, `blink`, `reverse`, `italic`, `strike`, optionally prefixed with
+    /// `no`/`no-` to clear it instead of setting it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap_builder as clap;
+    /// # use clap::builder::Styles;
+    /// let bold_red = Styles::parse_field("bold red").unwrap();
+    /// let underlined_orange = Styles::parse_field("ul #ff8800").unwrap();
+    /// ```
+    pub fn parse_field(spec: &str) -> Result<anstyle::Style, ParseStylesError> {
+        git_color_spec::parse(spec)
+    }
+
+    /// Populate `Styles` from an `LS_COLORS`-style environment spec
+    ///
+    /// The spec is `key=value:key=value:...`, where each `value` is a raw SGR parameter list
+    /// (e.g. `01;31` for bold red, `38;5;208` for a 256-color, `38;2;r;g;b` for truecolor).
+    /// `keys` says which key feeds which style slot; entries for unrecognized keys, and keys
+    /// with an unparsable value, are ignored. Slots with no matching entry are left unstyled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap_builder as clap;
+    /// # use clap::builder::{EnvSpecKeys, Styles};
+    /// let keys = EnvSpecKeys {
+    ///     header: "hd",
+    ///     literal: "li",
+    ///     placeholder: "ph",
+    ///     good: "gd",
+    ///     warning: "wn",
+    ///     error: "er",
+    ///     hint: "hi",
+    /// };
+    /// let styles = Styles::from_env_spec("hd=01;31:er=38;5;208", &keys);
+    /// ```
+    pub fn from_env_spec(spec: &str, keys: &EnvSpecKeys) -> Self {
+        let mut styles = Self::plain();
+
+        for entry in spec.split(':') {
+            let Some((key, value)) = entry.split_once('=') else {
+                continue;
+            };
+            let Some(style) = sgr_spec::parse(value) else {
+                continue;
+            };
+
+            if key == keys.header {
+                styles.header = style;
+            } else if key == keys.literal {
+                styles.literal = style;
+            } else if key == keys.placeholder {
+                styles.placeholder = style;
+            } else if key == keys.good {
+                styles.good = style;
+            } else if key == keys.warning {
+                styles.warning = style;
+            } else if key == keys.error {
+                styles.error = style;
+            } else if key == keys.hint {
+                styles.hint = style;
+            }
+        }
+
+        styles
+    }
+}
+
+/// Which environment-spec key feeds each [`Styles`] slot, for [`Styles::from_env_spec`]
+#[derive(Clone, Copy, Debug)]
+pub struct EnvSpecKeys {
+    /// Key for [`Styles::header`]
+    pub header: &'static str,
+    /// Key for [`Styles::literal`]
+    pub literal: &'static str,
+    /// Key for [`Styles::placeholder`]
+    pub placeholder: &'static str,
+    /// Key for [`Styles::good`]
+    pub good: &'static str,
+    /// Key for [`Styles::warning`]
+    pub warning: &'static str,
+    /// Key for [`Styles::error`]
+    pub error: &'static str,
+    /// Key for [`Styles::hint`]
+    pub hint: &'static str,
+}
+
+/// Interprets a raw SGR parameter list, e.g. `01;31` or `38;2;255;136;0`
+mod sgr_spec {
+    pub(super) fn parse(codes: &str) -> Option<anstyle::Style> {
+        let parts: Vec<u32> = codes
+            .split(';')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .ok()?;
+
+        let mut style = anstyle::Style::new();
+        let mut i = 0;
+        while i < parts.len() {
+            match parts[i] {
+                1 => style = style.bold(),
+                2 => style = style.dimmed(),
+                3 => style = style.italic(),
+                4 => style = style.underline(),
+                n @ 30..=37 => style = style.fg_color(Some(named_color((n - 30) as u8, false))),
+                n @ 90..=97 => style = style.fg_color(Some(named_color((n - 90) as u8, true))),
+                n @ 40..=47 => style = style.bg_color(Some(named_color((n - 40) as u8, false))),
+                n @ 100..=107 => style = style.bg_color(Some(named_color((n - 100) as u8, true))),
+                n @ (38 | 48) => {
+                    let is_fg = n == 38;
+                    let color = match parts.get(i + 1) {
+                        Some(5) => {
+                            let index = *parts.get(i + 2)? as u8;
+                            i += 2;
+                            anstyle::Color::Ansi256(anstyle::Ansi256Color(index))
+                        }
+                        Some(2) => {
+                            let r = *parts.get(i + 2)? as u8;
+                            let g = *parts.get(i + 3)? as u8;
+                            let b = *parts.get(i + 4)? as u8;
+                            i += 4;
+                            anstyle::Color::Rgb(anstyle::RgbColor(r, g, b))
+                        }
+                        _ => return None,
+                    };
+                    style = if is_fg {
+                        style.fg_color(Some(color))
+                    } else {
+                        style.bg_color(Some(color))
+                    };
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        Some(style)
+    }
+
+    fn named_color(index: u8, bright: bool) -> anstyle::Color {
+        const BASE: [anstyle::AnsiColor; 8] = [
+            anstyle::AnsiColor::Black,
+            anstyle::AnsiColor::Red,
+            anstyle::AnsiColor::Green,
+            anstyle::AnsiColor::Yellow,
+            anstyle::AnsiColor::Blue,
+            anstyle::AnsiColor::Magenta,
+            anstyle::AnsiColor::Cyan,
+            anstyle::AnsiColor::White,
+        ];
+        let color = BASE[index as usize];
+        let color = if bright {
+            super::git_color_spec::brighten(color)
+        } else {
+            color
+        };
+        anstyle::Color::Ansi(color)
+    }
+}
+
+/// Error parsing a git-style color spec, see [`Styles::parse_field`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseStylesError(String);
+
+impl std::fmt::Display for ParseStylesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for ParseStylesError {}
+
+/// Parses git's `color.<slot>` config grammar, e.g. `"bold red"` or `"ul #ff8800"`
+mod git_color_spec {
+    use super::ParseStylesError;
+
+    pub(super) fn parse(spec: &str) -> Result<anstyle::Style, ParseStylesError> {
+        let mut style = anstyle::Style::new();
+        let mut seen_colors = 0;
+
+        for token in spec.split_whitespace() {
+            if seen_colors < 2 {
+                if let Some(color) = parse_color(token) {
+                    style = if seen_colors == 0 {
+                        style.fg_color(color)
+                    } else {
+                        style.bg_color(color)
+                    };
+                    seen_colors += 1;
+                    continue;
+                }
+            }
+            style = apply_attr(style, token)?;
+        }
+
+        Ok(style)
+    }
+
+    fn parse_color(token: &str) -> Option<Option<anstyle::Color>> {
+        if token.eq_ignore_ascii_case("normal") {
+            return Some(None);
+        }
+        if let Some(hex) = token.strip_prefix('#') {
+            return parse_hex(hex).map(Some);
+        }
+        if let Ok(index) = token.parse::<u8>() {
+            return Some(Some(anstyle::Color::Ansi256(anstyle::Ansi256Color(index))));
+        }
+
+        let (bright, name) = match token.strip_prefix("bright") {
+            Some(rest) if !rest.is_empty() => (true, rest),
+            _ => (false, token),
+        };
+        let color = match name.to_ascii_lowercase().as_str() {
+            "black" => anstyle::AnsiColor::Black,
+            "red" => anstyle::AnsiColor::Red,
+            "green" => anstyle::AnsiColor::Green,
+            "yellow" => anstyle::AnsiColor::Yellow,
+            "blue" => anstyle::AnsiColor::Blue,
+            "magenta" => anstyle::AnsiColor::Magenta,
+            "cyan" => anstyle::AnsiColor::Cyan,
+            "white" => anstyle::AnsiColor::White,
+            _ => return None,
+        };
+        let color = if bright { brighten(color) } else { color };
+        Some(Some(anstyle::Color::Ansi(color)))
+    }
+
+    pub(super) fn brighten(color: anstyle::AnsiColor) -> anstyle::AnsiColor {
+        match color {
+            anstyle::AnsiColor::Black => anstyle::AnsiColor::BrightBlack,
+            anstyle::AnsiColor::Red => anstyle::AnsiColor::BrightRed,
+            anstyle::AnsiColor::Green => anstyle::AnsiColor::BrightGreen,
+            anstyle::AnsiColor::Yellow => anstyle::AnsiColor::BrightYellow,
+            anstyle::AnsiColor::Blue => anstyle::AnsiColor::BrightBlue,
+            anstyle::AnsiColor::Magenta => anstyle::AnsiColor::BrightMagenta,
+            anstyle::AnsiColor::Cyan => anstyle::AnsiColor::BrightCyan,
+            anstyle::AnsiColor::White => anstyle::AnsiColor::BrightWhite,
+            other => other,
+        }
+    }
+
+    fn parse_hex(hex: &str) -> Option<anstyle::Color> {
+        if hex.len() != 6 {
+            return None;
+        }
+        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+        Some(anstyle::Color::Rgb(anstyle::RgbColor(r, g, b)))
+    }
+
+    fn apply_attr(style: anstyle::Style, token: &str) -> Result<anstyle::Style, ParseStylesError> {
+        let (enable, name) = match token
+            .strip_prefix("no-")
+            .or_else(|| token.strip_prefix("no"))
+        {
+            Some(rest) if !rest.is_empty() => (false, rest),
+            _ => (true, token),
+        };
+
+        let effect = match name.to_ascii_lowercase().as_str() {
+            "bold" => anstyle::Effects::BOLD,
+            "dim" => anstyle::Effects::DIMMED,
+            "ul" | "underline" => anstyle::Effects::UNDERLINE,
+            "blink" => anstyle::Effects::BLINK,
+            "reverse" => anstyle::Effects::INVERT,
+            "italic" => anstyle::Effects::ITALIC,
+            "strike" => anstyle::Effects::STRIKETHROUGH,
+            _ => {
+                return Err(ParseStylesError(format!(
+                    "unrecognized color spec token `{token}`"
+                )));
+            }
+        };
+
+        let effects = if enable {
+            style.get_effects().insert(effect)
+        } else {
+            style.get_effects().remove(effect)
+        };
+        Ok(style.effects(effects))
+    }
 }
 
 impl super::AppTag for Styles {}
@@ -273,3 +934,448 @@ impl Default for &'_ Styles {
         &STYLES
     }
 }
+
+/// Renders a [`StyledStr`] via the Windows Console API on terminals that lack VT processing
+#[cfg(all(windows, feature = "color"))]
+mod windows_console {
+    use super::{color_degrade, Stream, StyledStr};
+
+    const FOREGROUND_BLUE: u16 = 0x0001;
+    const FOREGROUND_GREEN: u16 = 0x0002;
+    const FOREGROUND_RED: u16 = 0x0004;
+    const FOREGROUND_INTENSITY: u16 = 0x0008;
+    const BACKGROUND_BLUE: u16 = 0x0010;
+    const BACKGROUND_GREEN: u16 = 0x0020;
+    const BACKGROUND_RED: u16 = 0x0040;
+    const BACKGROUND_INTENSITY: u16 = 0x0080;
+
+    const FOREGROUND_BITS: [u16; 8] = [
+        0,
+        FOREGROUND_RED,
+        FOREGROUND_GREEN,
+        FOREGROUND_RED | FOREGROUND_GREEN,
+        FOREGROUND_BLUE,
+        FOREGROUND_RED | FOREGROUND_BLUE,
+        FOREGROUND_GREEN | FOREGROUND_BLUE,
+        FOREGROUND_RED | FOREGROUND_GREEN | FOREGROUND_BLUE,
+    ];
+    const BACKGROUND_BITS: [u16; 8] = [
+        0,
+        BACKGROUND_RED,
+        BACKGROUND_GREEN,
+        BACKGROUND_RED | BACKGROUND_GREEN,
+        BACKGROUND_BLUE,
+        BACKGROUND_RED | BACKGROUND_BLUE,
+        BACKGROUND_GREEN | BACKGROUND_BLUE,
+        BACKGROUND_RED | BACKGROUND_GREEN | BACKGROUND_BLUE,
+    ];
+
+    /// Write `styled` to `buffer`, using the Console API unless the console already has VT
+    /// processing enabled (in which case the raw ANSI escapes are fine as-is)
+    pub(super) fn write(
+        styled: &StyledStr,
+        stream: Stream,
+        buffer: &mut dyn std::io::Write,
+    ) -> std::io::Result<()> {
+        let Some(handle) = ffi::console_handle(stream) else {
+            return styled.write_to(buffer);
+        };
+        if ffi::vt_processing_enabled(handle) {
+            return styled.write_to(buffer);
+        }
+        let Some(default_attr) = ffi::current_attribute(handle) else {
+            return styled.write_to(buffer);
+        };
+
+        let mut state = ConsoleState::default();
+        let mut rest = styled.0.as_str();
+        while let Some(start) = rest.find("\x1b[") {
+            if start > 0 {
+                write_span(buffer, handle, &rest[..start], state, default_attr)?;
+            }
+            let after = &rest[start + 2..];
+            if let Some(end) = after.find('m') {
+                state.apply_params(&after[..end]);
+                rest = &after[end + 1..];
+            } else {
+                rest = after;
+            }
+        }
+        if !rest.is_empty() {
+            write_span(buffer, handle, rest, state, default_attr)?;
+        }
+
+        unsafe { ffi::SetConsoleTextAttribute(handle, default_attr) };
+
+        Ok(())
+    }
+
+    fn write_span(
+        buffer: &mut dyn std::io::Write,
+        handle: ffi::HANDLE,
+        text: &str,
+        state: ConsoleState,
+        default_attr: u16,
+    ) -> std::io::Result<()> {
+        // The attribute applies to the console, not to `buffer`, so no text may sit buffered
+        // across an attribute change
+        buffer.flush()?;
+        unsafe { ffi::SetConsoleTextAttribute(handle, state.to_attribute(default_attr)) };
+        buffer.write_all(text.as_bytes())?;
+        buffer.flush()
+    }
+
+    #[derive(Clone, Copy, Default)]
+    struct ConsoleState {
+        fg: Option<u8>,
+        bg: Option<u8>,
+        bold: bool,
+    }
+
+    impl ConsoleState {
+        fn apply_params(&mut self, params: &str) {
+            let codes: Vec<u32> = params
+                .split(';')
+                .map(|p| p.parse().unwrap_or(0))
+                .collect();
+
+            let mut i = 0;
+            while i < codes.len() {
+                match codes[i] {
+                    0 => *self = ConsoleState::default(),
+                    1 => self.bold = true,
+                    22 => self.bold = false,
+                    n @ 30..=37 => self.fg = Some((n - 30) as u8),
+                    n @ 90..=97 => self.fg = Some((n - 90) as u8 + 8),
+                    39 => self.fg = None,
+                    n @ 40..=47 => self.bg = Some((n - 40) as u8),
+                    n @ 100..=107 => self.bg = Some((n - 100) as u8 + 8),
+                    49 => self.bg = None,
+                    n @ (38 | 48) => {
+                        let is_fg = n == 38;
+                        match codes.get(i + 1) {
+                            Some(5) => {
+                                let idx = codes.get(i + 2).copied().unwrap_or(0) as u8;
+                                let rgb = color_degrade::ansi256_to_rgb(idx);
+                                let win = color_degrade::rgb_to_ansi16(rgb);
+                                if is_fg {
+                                    self.fg = Some(win);
+                                } else {
+                                    self.bg = Some(win);
+                                }
+                                i += 2;
+                            }
+                            Some(2) => {
+                                let rgb = (
+                                    codes.get(i + 2).copied().unwrap_or(0) as u8,
+                                    codes.get(i + 3).copied().unwrap_or(0) as u8,
+                                    codes.get(i + 4).copied().unwrap_or(0) as u8,
+                                );
+                                let win = color_degrade::rgb_to_ansi16(rgb);
+                                if is_fg {
+                                    self.fg = Some(win);
+                                } else {
+                                    self.bg = Some(win);
+                                }
+                                i += 4;
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+        }
+
+        fn to_attribute(self, default: u16) -> u16 {
+            let mut attr = default;
+            if let Some(fg) = self.fg {
+                attr = (attr & !0x000F)
+                    | FOREGROUND_BITS[(fg % 8) as usize]
+                    | if fg >= 8 || self.bold {
+                        FOREGROUND_INTENSITY
+                    } else {
+                        0
+                    };
+            } else if self.bold {
+                attr |= FOREGROUND_INTENSITY;
+            }
+            if let Some(bg) = self.bg {
+                attr = (attr & !0x00F0)
+                    | BACKGROUND_BITS[(bg % 8) as usize]
+                    | if bg >= 8 { BACKGROUND_INTENSITY } else { 0 };
+            }
+            attr
+        }
+    }
+
+    /// Minimal bindings for the handful of Windows Console API functions needed here
+    mod ffi {
+        use super::Stream;
+        use std::ffi::c_void;
+
+        pub(super) type HANDLE = *mut c_void;
+        type DWORD = u32;
+        type WORD = u16;
+        type BOOL = i32;
+
+        const STD_OUTPUT_HANDLE: DWORD = -11i32 as DWORD;
+        const STD_ERROR_HANDLE: DWORD = -12i32 as DWORD;
+        const ENABLE_VIRTUAL_TERMINAL_PROCESSING: DWORD = 0x0004;
+
+        #[repr(C)]
+        #[derive(Default)]
+        struct Coord {
+            x: i16,
+            y: i16,
+        }
+
+        #[repr(C)]
+        #[derive(Default)]
+        struct SmallRect {
+            left: i16,
+            top: i16,
+            right: i16,
+            bottom: i16,
+        }
+
+        #[repr(C)]
+        #[derive(Default)]
+        struct ConsoleScreenBufferInfo {
+            size: Coord,
+            cursor_position: Coord,
+            attributes: WORD,
+            window: SmallRect,
+            maximum_window_size: Coord,
+        }
+
+        extern "system" {
+            fn GetStdHandle(n_std_handle: DWORD) -> HANDLE;
+            fn GetConsoleMode(console_handle: HANDLE, mode: *mut DWORD) -> BOOL;
+            pub(super) fn SetConsoleTextAttribute(console_handle: HANDLE, attributes: WORD) -> BOOL;
+            fn GetConsoleScreenBufferInfo(
+                console_handle: HANDLE,
+                info: *mut ConsoleScreenBufferInfo,
+            ) -> BOOL;
+        }
+
+        pub(super) fn console_handle(stream: Stream) -> Option<HANDLE> {
+            let id = match stream {
+                Stream::Stdout => STD_OUTPUT_HANDLE,
+                Stream::Stderr => STD_ERROR_HANDLE,
+            };
+            let handle = unsafe { GetStdHandle(id) };
+            if handle.is_null() || handle as isize == -1 {
+                None
+            } else {
+                Some(handle)
+            }
+        }
+
+        pub(super) fn vt_processing_enabled(handle: HANDLE) -> bool {
+            let mut mode: DWORD = 0;
+            let ok = unsafe { GetConsoleMode(handle, &mut mode) };
+            ok == 0 || (mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0
+        }
+
+        pub(super) fn current_attribute(handle: HANDLE) -> Option<u16> {
+            let mut info = ConsoleScreenBufferInfo::default();
+            let ok = unsafe { GetConsoleScreenBufferInfo(handle, &mut info) };
+            if ok == 0 {
+                None
+            } else {
+                Some(info.attributes)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "color"))]
+mod color_degrade_tests {
+    use super::color_degrade;
+    use super::ColorCapability;
+
+    #[test]
+    fn ansi256_cube_round_trips() {
+        for idx in 16u8..=231 {
+            let rgb = color_degrade::ansi256_to_rgb(idx);
+            assert_eq!(color_degrade::rgb_to_ansi256(rgb), idx);
+        }
+    }
+
+    #[test]
+    fn ansi256_gray_ramp_round_trips() {
+        for idx in 232u8..=255 {
+            let rgb = color_degrade::ansi256_to_rgb(idx);
+            assert_eq!(color_degrade::rgb_to_ansi256(rgb), idx);
+        }
+    }
+
+    #[test]
+    fn downgrade_truecolor_is_passthrough() {
+        let input = "\x1b[38;2;10;20;30mhi\x1b[0m";
+        assert_eq!(
+            color_degrade::downgrade(input, ColorCapability::TrueColor).as_ref(),
+            input
+        );
+    }
+
+    #[test]
+    fn downgrade_to_ansi256() {
+        let input = "\x1b[38;2;255;0;0mhi\x1b[0m";
+        assert_eq!(
+            color_degrade::downgrade(input, ColorCapability::Ansi256).as_ref(),
+            "\x1b[38;5;196mhi\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn downgrade_to_ansi256_keeps_exact_named_16_index() {
+        // Index 1 (standard red) is already representable in the 256-color palette; it must
+        // not get rewritten to a merely-similar cube index like 160.
+        let input = "\x1b[38;5;1mhi\x1b[0m";
+        assert_eq!(
+            color_degrade::downgrade(input, ColorCapability::Ansi256).as_ref(),
+            input
+        );
+    }
+
+    #[test]
+    fn downgrade_to_ansi16() {
+        let input = "\x1b[38;2;255;0;0mhi\x1b[0m";
+        assert_eq!(
+            color_degrade::downgrade(input, ColorCapability::Ansi16).as_ref(),
+            "\x1b[91mhi\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn downgrade_to_mono_strips_color_but_keeps_attrs() {
+        let input = "\x1b[1;38;2;255;0;0mhi\x1b[0m";
+        assert_eq!(
+            color_degrade::downgrade(input, ColorCapability::Mono).as_ref(),
+            "\x1b[1mhi\x1b[0m"
+        );
+    }
+}
+
+#[cfg(test)]
+mod git_color_spec_tests {
+    use super::Styles;
+
+    #[test]
+    fn bold_and_named_fg() {
+        let style = Styles::parse_field("bold red").unwrap();
+        assert_eq!(
+            style,
+            anstyle::Style::new()
+                .bold()
+                .fg_color(Some(anstyle::Color::Ansi(anstyle::AnsiColor::Red)))
+        );
+    }
+
+    #[test]
+    fn bright_fg_and_bg() {
+        let style = Styles::parse_field("brightblue black").unwrap();
+        assert_eq!(
+            style,
+            anstyle::Style::new()
+                .fg_color(Some(anstyle::Color::Ansi(anstyle::AnsiColor::BrightBlue)))
+                .bg_color(Some(anstyle::Color::Ansi(anstyle::AnsiColor::Black)))
+        );
+    }
+
+    #[test]
+    fn hex_and_attr() {
+        let style = Styles::parse_field("ul #ff8800").unwrap();
+        assert_eq!(
+            style,
+            anstyle::Style::new()
+                .underline()
+                .fg_color(Some(anstyle::Color::Rgb(anstyle::RgbColor(0xff, 0x88, 0x00))))
+        );
+    }
+
+    #[test]
+    fn numeric_256_index() {
+        let style = Styles::parse_field("208").unwrap();
+        let color = anstyle::Color::Ansi256(anstyle::Ansi256Color(208));
+        assert_eq!(style, anstyle::Style::new().fg_color(Some(color)));
+    }
+
+    #[test]
+    fn normal_clears_fg() {
+        let style = Styles::parse_field("normal bold").unwrap();
+        assert_eq!(style, anstyle::Style::new().bold());
+    }
+
+    #[test]
+    fn no_prefix_clears_an_attribute() {
+        let enabled = Styles::parse_field("bold").unwrap();
+        let cleared = Styles::parse_field("bold nobold").unwrap();
+        assert_ne!(enabled, cleared);
+        assert_eq!(cleared, anstyle::Style::new());
+
+        let cleared_with_dash = Styles::parse_field("bold no-bold").unwrap();
+        assert_eq!(cleared, cleared_with_dash);
+    }
+
+    #[test]
+    fn unrecognized_token_is_an_error() {
+        assert!(Styles::parse_field("not-a-real-token").is_err());
+    }
+}
+
+#[cfg(test)]
+mod env_spec_tests {
+    use super::{sgr_spec, EnvSpecKeys, Styles};
+
+    const KEYS: EnvSpecKeys = EnvSpecKeys {
+        header: "hd",
+        literal: "li",
+        placeholder: "ph",
+        good: "gd",
+        warning: "wn",
+        error: "er",
+        hint: "hi",
+    };
+
+    #[test]
+    fn bold_red() {
+        let style = sgr_spec::parse("01;31").unwrap();
+        assert_eq!(
+            style,
+            anstyle::Style::new()
+                .bold()
+                .fg_color(Some(anstyle::Color::Ansi(anstyle::AnsiColor::Red)))
+        );
+    }
+
+    #[test]
+    fn ansi256_index() {
+        let style = sgr_spec::parse("38;5;208").unwrap();
+        let color = anstyle::Color::Ansi256(anstyle::Ansi256Color(208));
+        assert_eq!(style, anstyle::Style::new().fg_color(Some(color)));
+    }
+
+    #[test]
+    fn truecolor() {
+        let style = sgr_spec::parse("38;2;255;136;0").unwrap();
+        let color = anstyle::Color::Rgb(anstyle::RgbColor(255, 136, 0));
+        assert_eq!(style, anstyle::Style::new().fg_color(Some(color)));
+    }
+
+    #[test]
+    fn from_env_spec_ignores_unrecognized_and_honors_last_duplicate_key() {
+        let styles = Styles::from_env_spec("hd=01;31:xx=1;2;3:er=38;5;208:hd=1", &KEYS);
+
+        assert_eq!(styles.header, anstyle::Style::new().bold());
+        let error_color = anstyle::Color::Ansi256(anstyle::Ansi256Color(208));
+        assert_eq!(
+            styles.error,
+            anstyle::Style::new().fg_color(Some(error_color))
+        );
+        assert_eq!(styles.literal, anstyle::Style::new());
+    }
+}
@@ -15,7 +15,7 @@ use super::CompletionCandidate;
 ///
 /// #[derive(Debug, Parser)]
 /// struct Cli {
-///     #[arg(long, add = ArgValueCompleter::new(|| { vec![
+///     #[arg(long, add = ArgValueCompleter::new(|_current: &std::ffi::OsStr| { vec![
 ///         CompletionCandidate::new("foo"),
 ///         CompletionCandidate::new("bar"),
 ///         CompletionCandidate::new("baz")] }))]
@@ -35,8 +35,8 @@ impl ArgValueCompleter {
     }
 
     /// See [`CompletionCandidate`] for more information.
-    pub fn completions(&self) -> Vec<CompletionCandidate> {
-        self.0.completions()
+    pub fn completions(&self, current: &std::ffi::OsStr) -> Vec<CompletionCandidate> {
+        self.0.completions(current)
     }
 }
 
@@ -54,15 +54,19 @@ impl ArgExt for ArgValueCompleter {}
 pub trait CustomCompleter: Send + Sync {
     /// All potential candidates for an argument.
     ///
+    /// `current` is the word being completed, letting a completer scope its work (e.g. a
+    /// filesystem or API lookup) to the user's partial input instead of returning everything and
+    /// relying on the shell to filter it down.
+    ///
     /// See [`CompletionCandidate`] for more information.
-    fn completions(&self) -> Vec<CompletionCandidate>;
+    fn completions(&self, current: &std::ffi::OsStr) -> Vec<CompletionCandidate>;
 }
 
 impl<F> CustomCompleter for F
 where
-    F: Fn() -> Vec<CompletionCandidate> + Send + Sync,
+    F: Fn(&std::ffi::OsStr) -> Vec<CompletionCandidate> + Send + Sync,
 {
-    fn completions(&self) -> Vec<CompletionCandidate> {
-        self()
+    fn completions(&self, current: &std::ffi::OsStr) -> Vec<CompletionCandidate> {
+        self(current)
     }
 }
\ No newline at end of file